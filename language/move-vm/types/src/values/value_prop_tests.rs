@@ -1,9 +1,12 @@
 // Copyright (c) The Diem Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::values::{prop::layout_and_value_strategy, Value};
+use crate::values::{
+    prop::{layout_and_value_strategy, layout_strategy},
+    Value,
+};
 use move_core_types::value::MoveValue;
-use proptest::prelude::*;
+use proptest::{collection::vec, prelude::*};
 
 proptest! {
     #[test]
@@ -20,4 +23,36 @@ proptest! {
         let move_value_deserialized = MoveValue::simple_deserialize(&blob2, &layout).expect("must deserialize.");
         assert_eq!(move_value, move_value_deserialized);
     }
+
+    #[test]
+    fn serialized_size_matches_serialization((layout, value) in layout_and_value_strategy()) {
+        let blob = value.simple_serialize(&layout).expect("must serialize");
+        assert_eq!(value.serialized_size(&layout), Some(blob.len()));
+
+        assert_eq!(value.simple_serialize_bounded(&layout, blob.len()), Some(blob.clone()));
+        if !blob.is_empty() {
+            assert_eq!(value.simple_serialize_bounded(&layout, blob.len() - 1), None);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn deserialize_arbitrary_bytes(layout in layout_strategy(), blob in vec(any::<u8>(), 0..64)) {
+        // BCS is canonical, so anything that deserializes must serialize back to the same bytes.
+        if let Some(value) = Value::simple_deserialize(&blob, &layout) {
+            assert_eq!(value.simple_serialize(&layout), Some(blob));
+        }
+    }
+
+    #[test]
+    fn deserialize_truncated_blob((layout, value) in layout_and_value_strategy(), cut in any::<prop::sample::Index>()) {
+        let blob = value.simple_serialize(&layout).expect("must serialize");
+        if !blob.is_empty() {
+            let truncated = &blob[..cut.index(blob.len())];
+            assert!(Value::simple_deserialize(truncated, &layout).is_none());
+        }
+    }
 }
@@ -3,6 +3,7 @@
 
 use crate::values::*;
 use move_binary_format::errors::*;
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
 
 #[test]
 fn locals() -> PartialVMResult<()> {
@@ -120,3 +121,36 @@ fn global_value_non_struct() -> PartialVMResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn simple_serialize_bounded_nested_struct() {
+    const DEPTH: usize = 64;
+    const BYTES_PER_LEVEL: usize = 1024;
+
+    // Each level is `struct { vector<u8>, inner }`, bottoming out in a u64.
+    let mut layout = MoveTypeLayout::U64;
+    let mut value = Value::u64(0);
+    for _ in 0..DEPTH {
+        layout = MoveTypeLayout::Struct(MoveStructLayout::new(vec![
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            layout,
+        ]));
+        value = Value::struct_(Struct::pack(vec![
+            Value::vector_u8(vec![0xFF; BYTES_PER_LEVEL]),
+            value,
+        ]));
+    }
+
+    let size = value.serialized_size(&layout).unwrap();
+    assert!(size > DEPTH * BYTES_PER_LEVEL);
+    assert_eq!(value.simple_serialize(&layout).unwrap().len(), size);
+
+    assert!(value.simple_serialize_bounded(&layout, size - 1).is_none());
+    assert!(value
+        .simple_serialize_bounded(&layout, BYTES_PER_LEVEL)
+        .is_none());
+    assert_eq!(
+        value.simple_serialize_bounded(&layout, size).unwrap().len(),
+        size
+    );
+}
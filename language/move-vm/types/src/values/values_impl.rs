@@ -2354,6 +2354,75 @@ impl Value {
         })
         .ok()
     }
+
+    /// Returns the exact length of the output of `simple_serialize`, computed without
+    /// allocating the output buffer.
+    pub fn serialized_size(&self, layout: &MoveTypeLayout) -> Option<usize> {
+        let mut writer = LimitedWriter::new(std::io::sink(), usize::MAX);
+        self.serialize_into(layout, &mut writer)?;
+        Some(writer.written)
+    }
+
+    /// Same as `simple_serialize`, but fails as soon as the serialized value exceeds `max`
+    /// bytes, so the output buffer never grows much beyond the budget.
+    pub fn simple_serialize_bounded(&self, layout: &MoveTypeLayout, max: usize) -> Option<Vec<u8>> {
+        let mut writer = LimitedWriter::new(vec![], max);
+        self.serialize_into(layout, &mut writer)?;
+        Some(writer.inner)
+    }
+
+    fn serialize_into<W: std::io::Write>(
+        &self,
+        layout: &MoveTypeLayout,
+        writer: &mut W,
+    ) -> Option<()> {
+        bcs::serialize_into(
+            writer,
+            &AnnotatedValue {
+                layout,
+                val: &self.0,
+            },
+        )
+        .ok()
+    }
+}
+
+/// A writer that forwards to `inner` and errors out once more than `limit` bytes have been
+/// written in total.
+struct LimitedWriter<W> {
+    inner: W,
+    written: usize,
+    limit: usize,
+}
+
+impl<W> LimitedWriter<W> {
+    fn new(inner: W, limit: usize) -> Self {
+        Self {
+            inner,
+            written: 0,
+            limit,
+        }
+    }
+}
+
+impl<W: std::io::Write> std::io::Write for LimitedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.written.checked_add(buf.len()) {
+            Some(written) if written <= self.limit => {
+                self.inner.write_all(buf)?;
+                self.written = written;
+                Ok(buf.len())
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "serialization size limit exceeded",
+            )),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
 }
 
 impl Struct {
@@ -2721,7 +2790,7 @@ pub mod prop {
         leaf.prop_recursive(8, 32, 2, |inner| {
             prop_oneof![
                 1 => inner.clone().prop_map(|layout| L::Vector(Box::new(layout))),
-                1 => vec(inner, 0..1).prop_map(|f_layouts| {
+                1 => vec(inner, 1..4).prop_map(|f_layouts| {
                      L::Struct(MoveStructLayout::new(f_layouts))}),
             ]
         })